@@ -78,40 +78,502 @@ pub trait Node {
 /// ];
 /// ```
 pub fn render<T: Node>(node: &T) -> Vec<String> {
-    let mut lines = vec![node.name().to_owned()];
-    let mut children = node.children();
-    let maybe_last_child = children.next_back();
-    let non_last_children: Vec<&T> = children.collect();
-    if let Some(last_child) = maybe_last_child {
-        let child_node_lines = non_last_children.iter().flat_map(|child| {
-            render(*child)
-                .iter()
-                .enumerate()
-                .map(|(idx, child_line)| {
-                    if idx == 0 {
-                        format!("├── {}", child_line)
+    render_with(node, &RenderOptions::default())
+}
+
+/// Renders the given [`Node`] like [`render`], but using custom
+/// [`RenderOptions`] instead of the `tree(1)`-style defaults.
+pub fn render_with<T: Node>(node: &T, options: &RenderOptions) -> Vec<String> {
+    lines_with(node, options).collect()
+}
+
+/// Renders the given [`Node`] like [`render`], then appends a blank line
+/// and a tally of every node in the tree grouped by `classify`, similar to
+/// how `tree(1)` ends its output with a line like `8 directories, 13 files`.
+///
+/// # Example
+///
+/// ```
+/// use render_as_tree::{render_with_footer, Node};
+/// # struct BasicNode { name: String, children: Vec<BasicNode>, is_dir: bool }
+/// # impl Node for BasicNode {
+/// #     type I<'a> = std::slice::Iter<'a, Self>;
+/// #     fn name(&self) -> &str { &self.name }
+/// #     fn children(&self) -> Self::I<'_> { self.children.iter() }
+/// # }
+/// # let root = BasicNode { name: String::new(), children: Vec::new(), is_dir: true };
+/// render_with_footer(&root, |node| if node.is_dir { "directories" } else { "files" });
+/// ```
+pub fn render_with_footer<T, C, F>(node: &T, classify: F) -> Vec<String>
+where
+    T: Node,
+    C: std::fmt::Display + Eq,
+    F: Fn(&T) -> C,
+{
+    let mut lines = render(node);
+
+    let mut counts: Vec<(C, usize)> = Vec::new();
+    walk(node, &mut |visited, _depth| {
+        let category = classify(visited);
+        match counts.iter_mut().find(|(c, _)| *c == category) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((category, 1)),
+        }
+    });
+
+    lines.push(String::new());
+    lines.push(
+        counts
+            .into_iter()
+            .map(|(category, count)| format!("{count} {category}"))
+            .collect::<Vec<_>>()
+            .join(", "),
+    );
+    lines
+}
+
+/// Visits every node in the tree, parent before children, passing each
+/// node's depth below `root` (which is 0). Uses an explicit stack rather
+/// than recursion, for the same reason [`Lines`] does: a very deep tree
+/// cannot overflow the call stack.
+fn walk<T: Node>(root: &T, visit: &mut impl FnMut(&T, usize)) {
+    let mut stack = vec![(root, 0)];
+    while let Some((node, depth)) = stack.pop() {
+        visit(node, depth);
+        for child in node.children().rev() {
+            stack.push((child, depth + 1));
+        }
+    }
+}
+
+/// Lazily renders the given [`Node`], yielding one formatted line per
+/// [`Iterator::next`] call instead of building the whole [`Vec`] up front.
+///
+/// This is equivalent to [`render`] but avoids allocating a line for every
+/// node in the tree before the caller can use any of them, which matters for
+/// very large trees or when lines are being streamed straight into
+/// `println!` or a TUI.
+pub fn lines<T: Node>(node: &T) -> Lines<'_, T> {
+    lines_with(node, &RenderOptions::default())
+}
+
+/// Lazily renders the given [`Node`] like [`lines`], but using custom
+/// [`RenderOptions`] instead of the `tree(1)`-style defaults.
+pub fn lines_with<'a, T: Node>(node: &'a T, options: &RenderOptions) -> Lines<'a, T> {
+    Lines::new(node, options.clone())
+}
+
+/// Output backend for [`render_as`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The `tree(1)`-style connector output produced by [`render`].
+    Tree,
+    /// A nested HTML `<ul>`/`<li>` tree, with each node's name HTML-escaped.
+    /// Useful for embedding a tree in a web dashboard or Markdown docs.
+    Html,
+    /// Plain text, indented two spaces per depth level.
+    Indent,
+}
+
+/// Renders the given [`Node`] using the selected [`Format`] and returns it
+/// as a single string.
+pub fn render_as<T: Node>(node: &T, format: Format) -> String {
+    match format {
+        Format::Tree => render(node).join("\n"),
+        Format::Html => {
+            let mut html = String::new();
+            push_html(node, &mut html);
+            html
+        }
+        Format::Indent => {
+            let mut lines = Vec::new();
+            push_indent_lines(node, &mut lines);
+            lines.join("\n")
+        }
+    }
+}
+
+/// Builds the nested `<ul>`/`<li>` markup for `root`. Uses an explicit
+/// stack rather than recursion, for the same reason [`Lines`] does: each
+/// node is pushed as an "open" item, which, once all its children have been
+/// written, is followed by a matching "close" item that writes its closing
+/// tags.
+fn push_html<T: Node>(root: &T, html: &mut String) {
+    enum Item<'a, T: Node> {
+        Open(&'a T),
+        Close { had_children: bool },
+    }
+
+    let mut stack = vec![Item::Open(root)];
+    while let Some(item) = stack.pop() {
+        match item {
+            Item::Open(node) => {
+                html.push_str("<li>");
+                html.push_str(&escape_html(node.name()));
+                let mut children = node.children().peekable();
+                let had_children = children.peek().is_some();
+                if had_children {
+                    html.push_str("<ul>");
+                }
+                stack.push(Item::Close { had_children });
+                for child in children.rev() {
+                    stack.push(Item::Open(child));
+                }
+            }
+            Item::Close { had_children } => {
+                if had_children {
+                    html.push_str("</ul>");
+                }
+                html.push_str("</li>");
+            }
+        }
+    }
+}
+
+fn escape_html(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn push_indent_lines<T: Node>(root: &T, lines: &mut Vec<String>) {
+    walk(root, &mut |node, depth| {
+        lines.push(format!("{}{}", "  ".repeat(depth), node.name()));
+    });
+}
+
+/// Configures the glyphs used to draw branch connectors when rendering a
+/// tree, for terminals, locales, or indentation widths that the `tree(1)`-
+/// style defaults don't suit.
+///
+/// Build one with [`RenderOptions::builder`], or use a ready-made preset
+/// such as [`RenderOptions::ascii`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderOptions {
+    tee: String,
+    corner: String,
+    vertical: String,
+    blank: String,
+    max_depth: Option<usize>,
+    ellipsis: String,
+}
+
+impl RenderOptions {
+    /// Starts building a custom [`RenderOptions`].
+    pub fn builder() -> RenderOptionsBuilder {
+        RenderOptionsBuilder::default()
+    }
+
+    /// A preset using only ASCII characters (`|--`, `` `-- ``, `|  `), for
+    /// terminals or locales without good Unicode box-drawing support.
+    pub fn ascii() -> Self {
+        RenderOptions::builder()
+            .tee("|-- ")
+            .corner("`-- ")
+            .vertical("|   ")
+            .blank("    ")
+            .build()
+            .expect("ascii preset glyphs share a display width")
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        RenderOptions::builder()
+            .build()
+            .expect("default glyphs share a display width")
+    }
+}
+
+/// Builder for [`RenderOptions`]. Construct via [`RenderOptions::builder`].
+#[derive(Debug, Clone)]
+pub struct RenderOptionsBuilder {
+    tee: String,
+    corner: String,
+    vertical: String,
+    blank: String,
+    max_depth: Option<usize>,
+    ellipsis: String,
+}
+
+impl Default for RenderOptionsBuilder {
+    fn default() -> Self {
+        RenderOptionsBuilder {
+            tee: String::from("├── "),
+            corner: String::from("└── "),
+            vertical: String::from("│   "),
+            blank: String::from("    "),
+            max_depth: None,
+            ellipsis: String::from("…"),
+        }
+    }
+}
+
+impl RenderOptionsBuilder {
+    /// Sets the connector drawn before a child that has following siblings.
+    pub fn tee(mut self, tee: impl Into<String>) -> Self {
+        self.tee = tee.into();
+        self
+    }
+
+    /// Sets the connector drawn before the last child among its siblings.
+    pub fn corner(mut self, corner: impl Into<String>) -> Self {
+        self.corner = corner.into();
+        self
+    }
+
+    /// Sets the continuation drawn under an ancestor that still has
+    /// following siblings.
+    pub fn vertical(mut self, vertical: impl Into<String>) -> Self {
+        self.vertical = vertical.into();
+        self
+    }
+
+    /// Sets the blank spacer drawn under an ancestor that was the last
+    /// among its siblings.
+    pub fn blank(mut self, blank: impl Into<String>) -> Self {
+        self.blank = blank.into();
+        self
+    }
+
+    /// Limits how many levels below the root are expanded. A node sitting
+    /// at the cutoff whose children would otherwise be drawn instead gets a
+    /// single synthetic [`ellipsis`](Self::ellipsis) line in their place.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Sets the marker drawn in place of a subtree elided by
+    /// [`max_depth`](Self::max_depth). Defaults to `"…"`.
+    pub fn ellipsis(mut self, ellipsis: impl Into<String>) -> Self {
+        self.ellipsis = ellipsis.into();
+        self
+    }
+
+    /// Builds the [`RenderOptions`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RenderOptionsError::InconsistentGlyphWidth`] unless the
+    /// tee, corner, vertical, and blank glyphs all have the same display
+    /// width, since otherwise sibling columns wouldn't line up.
+    pub fn build(self) -> Result<RenderOptions, RenderOptionsError> {
+        let widths = [
+            display_width(&self.tee),
+            display_width(&self.corner),
+            display_width(&self.vertical),
+            display_width(&self.blank),
+        ];
+        if widths.iter().any(|&width| width != widths[0]) {
+            return Err(RenderOptionsError::InconsistentGlyphWidth);
+        }
+        Ok(RenderOptions {
+            tee: self.tee,
+            corner: self.corner,
+            vertical: self.vertical,
+            blank: self.blank,
+            max_depth: self.max_depth,
+            ellipsis: self.ellipsis,
+        })
+    }
+}
+
+/// The number of terminal columns a string occupies. This crate has no
+/// dependencies, so rather than pull in `unicode-width` for this alone, it
+/// covers the common wide ranges (CJK, Hangul, fullwidth forms, emoji) and
+/// treats zero-width marks as zero columns; anything else counts as one
+/// column.
+fn display_width(s: &str) -> usize {
+    s.chars().map(char_display_width).sum()
+}
+
+fn char_display_width(c: char) -> usize {
+    let cp = c as u32;
+    const ZERO_WIDTH: &[(u32, u32)] = &[
+        (0x0300, 0x036F), // combining diacritical marks
+        (0x200B, 0x200D), // zero-width space/non-joiner/joiner
+        (0xFE0E, 0xFE0F), // variation selectors
+    ];
+    const WIDE: &[(u32, u32)] = &[
+        (0x1100, 0x115F),   // Hangul Jamo
+        (0x2E80, 0x303E),   // CJK radicals & symbols
+        (0x3041, 0x33FF),   // Hiragana .. CJK compatibility
+        (0x3400, 0x4DBF),   // CJK Unified Ideographs Extension A
+        (0x4E00, 0x9FFF),   // CJK Unified Ideographs
+        (0xA000, 0xA4CF),   // Yi
+        (0xAC00, 0xD7A3),   // Hangul syllables
+        (0xF900, 0xFAFF),   // CJK compatibility ideographs
+        (0xFF00, 0xFF60),   // fullwidth forms
+        (0xFFE0, 0xFFE6),   // fullwidth signs
+        (0x1F300, 0x1FAFF), // misc symbols, pictographs & emoji
+        (0x20000, 0x3FFFD), // CJK Unified Ideographs Extension B+
+    ];
+    if ZERO_WIDTH.iter().any(|&(lo, hi)| (lo..=hi).contains(&cp)) {
+        0
+    } else if WIDE.iter().any(|&(lo, hi)| (lo..=hi).contains(&cp)) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Error returned by [`RenderOptionsBuilder::build`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenderOptionsError {
+    /// The tee, corner, vertical, and blank glyphs must all share the same
+    /// display width, or sibling columns won't line up.
+    InconsistentGlyphWidth,
+}
+
+impl std::fmt::Display for RenderOptionsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RenderOptionsError::InconsistentGlyphWidth => write!(
+                f,
+                "tee, corner, vertical, and blank glyphs must share a display width"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RenderOptionsError {}
+
+/// Iterator returned by [`lines`] and [`lines_with`]. Walks the tree
+/// depth-first using an explicit stack rather than recursion, so a very deep
+/// tree cannot overflow the call stack. The stack holds only the siblings
+/// not yet drawn of the current node and of each of its ancestors, never
+/// the lines already produced.
+pub struct Lines<'a, T: Node> {
+    // Each frame is a node, or an ellipsis marker for an elided subtree,
+    // still awaiting its turn; see `Frame`.
+    stack: Vec<Frame<'a, T>>,
+    options: RenderOptions,
+}
+
+enum Frame<'a, T: Node> {
+    /// A node still awaiting its turn, along with whether it's the last
+    /// among its siblings, its depth below the root, and the same "was
+    /// last" flag for every ancestor between it and the root, not counting
+    /// the root itself, which draws no column (innermost ancestor last),
+    /// which together are enough to reconstruct its line's prefix without
+    /// walking back up a call stack.
+    Node {
+        node: &'a T,
+        is_root: bool,
+        is_last: bool,
+        depth: usize,
+        ancestors_last: Vec<bool>,
+    },
+    /// A synthetic stand-in for a subtree elided by [`RenderOptions::max_depth`].
+    /// Always drawn as the last (and only) child.
+    Ellipsis { ancestors_last: Vec<bool> },
+}
+
+impl<'a, T: Node> Lines<'a, T> {
+    fn new(root: &'a T, options: RenderOptions) -> Self {
+        Lines {
+            stack: vec![Frame::Node {
+                node: root,
+                is_root: true,
+                is_last: true,
+                depth: 0,
+                ancestors_last: Vec::new(),
+            }],
+            options,
+        }
+    }
+}
+
+impl<'a, T: Node> Iterator for Lines<'a, T> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        match self.stack.pop()? {
+            Frame::Ellipsis { ancestors_last } => {
+                let mut line = String::new();
+                for &ancestor_was_last in &ancestors_last {
+                    line.push_str(if ancestor_was_last {
+                        &self.options.blank
                     } else {
-                        format!("│   {}", child_line)
+                        &self.options.vertical
+                    });
+                }
+                line.push_str(&self.options.corner);
+                line.push_str(&self.options.ellipsis);
+                Some(line)
+            }
+            Frame::Node {
+                node,
+                is_root,
+                is_last,
+                depth,
+                ancestors_last,
+            } => {
+                // The root has no parent, so it gets no prefix at all.
+                let line = if is_root {
+                    node.name().to_owned()
+                } else {
+                    let mut line = String::new();
+                    for &ancestor_was_last in &ancestors_last {
+                        line.push_str(if ancestor_was_last {
+                            &self.options.blank
+                        } else {
+                            &self.options.vertical
+                        });
                     }
-                })
-                .collect::<Vec<String>>()
-        });
-        let last_child_node_lines = render(last_child);
-        let formatted_last_child_node_lines_iter =
-            last_child_node_lines
-                .iter()
-                .enumerate()
-                .map(|(idx, child_line)| {
-                    if idx == 0 {
-                        format!("└── {}", child_line)
+                    line.push_str(if is_last {
+                        &self.options.corner
                     } else {
-                        format!("    {}", child_line)
+                        &self.options.tee
+                    });
+                    line.push_str(node.name());
+                    line
+                };
+
+                let child_ancestors_last = if is_root {
+                    Vec::new()
+                } else {
+                    let mut ancestors_last = ancestors_last;
+                    ancestors_last.push(is_last);
+                    ancestors_last
+                };
+
+                let mut children = node.children().peekable();
+                let at_depth_limit = self.options.max_depth.is_some_and(|max| depth >= max);
+                if at_depth_limit && children.peek().is_some() {
+                    self.stack.push(Frame::Ellipsis {
+                        ancestors_last: child_ancestors_last,
+                    });
+                } else {
+                    // Push children in reverse order so that, as a stack,
+                    // they pop back off in their original order.
+                    let mut is_last_child = true;
+                    for child in children.rev() {
+                        self.stack.push(Frame::Node {
+                            node: child,
+                            is_root: false,
+                            is_last: is_last_child,
+                            depth: depth + 1,
+                            ancestors_last: child_ancestors_last.clone(),
+                        });
+                        is_last_child = false;
                     }
-                });
-        let children_lines = child_node_lines.chain(formatted_last_child_node_lines_iter);
-        lines.extend(children_lines);
+                }
+
+                Some(line)
+            }
+        }
     }
-    lines
 }
 
 #[cfg(test)]
@@ -188,4 +650,180 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn lines_matches_render() {
+        let root = BasicNode {
+            name: String::from("root"),
+            children: vec![
+                BasicNode {
+                    name: String::from("child 1"),
+                    children: vec![BasicNode::new(String::from("grandchild 1A"))],
+                },
+                BasicNode::new(String::from("child 2")),
+            ],
+        };
+        assert_eq!(lines(&root).collect::<Vec<String>>(), render(&root));
+    }
+
+    #[test]
+    fn ascii_preset() {
+        let root = BasicNode {
+            name: String::from("root"),
+            children: vec![
+                BasicNode::new(String::from("child 1")),
+                BasicNode::new(String::from("child 2")),
+            ],
+        };
+        assert_eq!(
+            render_with(&root, &RenderOptions::ascii()),
+            vec![
+                String::from("root"),
+                String::from("|-- child 1"),
+                String::from("`-- child 2"),
+            ]
+        );
+    }
+
+    #[test]
+    fn inconsistent_glyph_width_is_rejected() {
+        assert_eq!(
+            RenderOptions::builder().tee("->").build(),
+            Err(RenderOptionsError::InconsistentGlyphWidth)
+        );
+    }
+
+    #[test]
+    fn inconsistent_display_width_is_rejected_even_with_equal_char_count() {
+        // "🌲   " and "├── " both have 4 `char`s, but the tree emoji is
+        // double-width on a terminal, so the columns still wouldn't line up.
+        assert_eq!(
+            RenderOptions::builder()
+                .tee("├── ")
+                .corner("🌲   ")
+                .vertical("│   ")
+                .blank("    ")
+                .build(),
+            Err(RenderOptionsError::InconsistentGlyphWidth)
+        );
+    }
+
+    #[test]
+    fn render_as_html_escapes_names_and_nests_lists() {
+        let root = BasicNode {
+            name: String::from("<root>"),
+            children: vec![
+                BasicNode::new(String::from("a & b")),
+                BasicNode {
+                    name: String::from("child 2"),
+                    children: vec![BasicNode::new(String::from("grandchild"))],
+                },
+            ],
+        };
+        assert_eq!(
+            render_as(&root, Format::Html),
+            "<li>&lt;root&gt;<ul><li>a &amp; b</li><li>child 2<ul><li>grandchild</li></ul></li></ul></li>"
+        );
+    }
+
+    #[test]
+    fn render_as_indent() {
+        let root = BasicNode {
+            name: String::from("root"),
+            children: vec![BasicNode {
+                name: String::from("child"),
+                children: vec![BasicNode::new(String::from("grandchild"))],
+            }],
+        };
+        assert_eq!(
+            render_as(&root, Format::Indent),
+            "root\n  child\n    grandchild"
+        );
+    }
+
+    #[test]
+    fn render_as_tree_matches_render() {
+        let root = BasicNode::new(String::from("beans"));
+        assert_eq!(render_as(&root, Format::Tree), render(&root).join("\n"));
+    }
+
+    fn deeply_nested_tree() -> BasicNode {
+        BasicNode {
+            name: String::from("root"),
+            children: vec![BasicNode {
+                name: String::from("child"),
+                children: vec![BasicNode {
+                    name: String::from("grandchild"),
+                    children: vec![BasicNode::new(String::from("great-grandchild"))],
+                }],
+            }],
+        }
+    }
+
+    #[test]
+    fn max_depth_elides_deeper_subtrees() {
+        let root = deeply_nested_tree();
+        let options = RenderOptions::builder().max_depth(1).build().unwrap();
+        assert_eq!(
+            render_with(&root, &options),
+            vec![
+                String::from("root"),
+                String::from("└── child"),
+                String::from("    └── …"),
+            ]
+        );
+    }
+
+    #[test]
+    fn max_depth_zero_renders_only_root() {
+        let root = deeply_nested_tree();
+        let options = RenderOptions::builder().max_depth(0).build().unwrap();
+        assert_eq!(
+            render_with(&root, &options),
+            vec![String::from("root"), String::from("└── …")]
+        );
+    }
+
+    #[test]
+    fn custom_ellipsis() {
+        let root = deeply_nested_tree();
+        let options = RenderOptions::builder()
+            .max_depth(0)
+            .ellipsis("...")
+            .build()
+            .unwrap();
+        assert_eq!(
+            render_with(&root, &options),
+            vec![String::from("root"), String::from("└── ...")]
+        );
+    }
+
+    #[test]
+    fn render_with_footer_tallies_by_category() {
+        let root = BasicNode {
+            name: String::from("root"),
+            children: vec![
+                BasicNode {
+                    name: String::from("dir 1"),
+                    children: vec![BasicNode::new(String::from("file 1"))],
+                },
+                BasicNode::new(String::from("file 2")),
+            ],
+        };
+        assert_eq!(
+            render_with_footer(&root, |node| if node.children.is_empty() {
+                "files"
+            } else {
+                "directories"
+            }),
+            vec![
+                String::from("root"),
+                String::from("├── dir 1"),
+                String::from("│   └── file 1"),
+                String::from("└── file 2"),
+                String::new(),
+                String::from("2 directories, 2 files"),
+            ]
+        );
+    }
 }